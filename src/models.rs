@@ -27,6 +27,53 @@ pub struct BlobResourceContents {
     pub mime_type: Option<String>,
     /// The URI of this resource.
     pub uri: String,
+    /// Lets a client verify `blob` wasn't tampered with in transit or cache.
+    pub integrity: Option<ResourceIntegrity>,
+}
+
+/// Hash algorithm used to compute a `ResourceIntegrity` digest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DigestAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+/// A detached signature over a resource's digest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Signature {
+    pub key_id: String,
+    pub public_key_pem: Option<String>,
+    pub value: String,
+}
+
+/// Proves a resource's content matches what the server originally published.
+/// `digest` is the base64-encoded hash of the decoded content; `signature.value`,
+/// when present, signs that digest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceIntegrity {
+    pub algorithm: DigestAlgorithm,
+    pub digest: String,
+    pub signature: Option<Signature>,
+}
+
+impl ResourceIntegrity {
+    /// Recomputes the digest of `content` under `algorithm` and checks it against `digest`.
+    pub fn verify_digest(&self, content: &[u8]) -> bool {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        let computed = match self.algorithm {
+            DigestAlgorithm::Sha256 => {
+                use sha2::{Digest, Sha256};
+                STANDARD.encode(Sha256::digest(content))
+            }
+            DigestAlgorithm::Sha512 => {
+                use sha2::{Digest, Sha512};
+                STANDARD.encode(Sha512::digest(content))
+            }
+        };
+        computed == self.digest
+    }
 }
 
 /// Used by the client to invoke a tool provided by the server.
@@ -40,16 +87,74 @@ pub struct CallToolRequest {
 pub struct CallToolParams {
     pub name: String,
     pub arguments: Option<HashMap<String, serde_json::Value>>,
+    #[serde(rename = "_meta")]
+    pub meta: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// Correlates progress notifications with the call that requested them.
+/// A client attaches this in the `_meta` field of `CallToolParams` to opt in
+/// to incremental progress updates for that call.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum ProgressToken {
+    String(String),
+    Number(i64),
+}
+
+/// Reports incremental progress on a long-running operation identified by its `progress_token`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressNotification {
+    pub progress_token: ProgressToken,
+    pub progress: f64,
+    pub total: Option<f64>,
+    pub message: Option<String>,
+}
+
+/// Tells the receiver that the request identified by `request_id` should be abandoned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelledNotification {
+    pub request_id: RequestId,
+    pub reason: Option<String>,
+}
+
+/// Sent by the client to begin the handshake with a server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitializeRequest {
+    pub method: String, // const "initialize"
+    pub params: InitializeParams,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitializeParams {
+    pub capabilities: ClientCapabilities,
+    pub client_info: Implementation,
+    pub protocol_version: String,
+    /// Other protocol revisions this client can fall back to if the server doesn't
+    /// support `protocol_version`.
+    pub supported_protocol_versions: Option<Vec<String>>,
 }
 
 /// After receiving an initialize request from the client, the server sends this response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InitializeResult {
     pub capabilities: ServerCapabilities,
+    pub server_info: Implementation,
+    pub protocol_version: String,
+    /// Other protocol revisions this server can fall back to if the client doesn't
+    /// support `protocol_version`.
+    pub supported_protocol_versions: Option<Vec<String>>,
     #[serde(rename = "_meta")]
     pub meta: Option<HashMap<String, serde_json::Value>>,
 }
 
+/// Identifies the software on one side of the initialize handshake.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Implementation {
+    pub name: String,
+    pub version: String,
+    pub homepage: Option<String>,
+}
+
 /// The server's response to a tool call.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CallToolResult {
@@ -92,6 +197,8 @@ pub struct Resource {
     pub name: Option<String>,
     pub mime_type: Option<String>,
     pub annotations: Option<Annotations>,
+    /// Lets a client verify this resource's content wasn't tampered with in transit or cache.
+    pub integrity: Option<ResourceIntegrity>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -121,6 +228,39 @@ pub struct ServerCapabilities {
     pub experimental: Option<HashMap<String, HashMap<String, serde_json::Value>>>,
     pub tools: Option<Vec<Tool>>,
     pub prompts: Option<Vec<ResourceTemplate>>,
+    /// Whether the server supports emitting `ProgressNotification`s for tool calls.
+    pub progress: Option<bool>,
+    /// Arbitrary capability payload describing the server's logging support.
+    pub logging: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// Severity of a log message, ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoggingLevel {
+    Debug,
+    Info,
+    Notice,
+    Warning,
+    Error,
+    Critical,
+    Alert,
+    Emergency,
+}
+
+/// Sent by the client to ask the server to only emit log messages at or above `level`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetLevelRequest {
+    pub method: String, // const "logging/setLevel"
+    pub level: LoggingLevel,
+}
+
+/// A graded diagnostic emitted by the server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingMessageNotification {
+    pub level: LoggingLevel,
+    pub logger: Option<String>,
+    pub data: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -132,6 +272,137 @@ pub struct Tool {
     pub annotations: Option<Annotations>,
 }
 
+/// An OpenRPC 1.3.2 service description, generated from a server's advertised tools.
+/// See <https://spec.open-rpc.org/>.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenRpcDocument {
+    pub openrpc: String,
+    pub info: OpenRpcInfo,
+    pub methods: Vec<OpenRpcMethod>,
+    pub components: OpenRpcComponents,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenRpcInfo {
+    pub title: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenRpcMethod {
+    pub name: String,
+    pub params: Vec<OpenRpcContentDescriptor>,
+    pub result: OpenRpcContentDescriptor,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenRpcContentDescriptor {
+    pub name: String,
+    pub required: bool,
+    pub schema: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenRpcComponents {
+    pub schemas: HashMap<String, serde_json::Value>,
+}
+
+/// Generates an OpenRPC service document describing the tools a server advertises.
+/// Each `Tool` becomes a method whose params are derived from the top-level properties
+/// of `parameters`, and whose result wraps `returns`. Sub-schemas that carry a `title`
+/// are hoisted into `components.schemas`, deduplicated by title, and replaced by a `$ref`.
+pub fn to_openrpc(capabilities: &ServerCapabilities) -> OpenRpcDocument {
+    let mut schemas = HashMap::new();
+
+    let methods = capabilities
+        .tools
+        .iter()
+        .flatten()
+        .map(|tool| {
+            let params = tool
+                .parameters
+                .get("properties")
+                .and_then(|p| p.as_object())
+                .map(|props| {
+                    let required: Vec<&str> = tool
+                        .parameters
+                        .get("required")
+                        .and_then(|r| r.as_array())
+                        .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect())
+                        .unwrap_or_default();
+                    props
+                        .iter()
+                        .map(|(name, schema)| OpenRpcContentDescriptor {
+                            name: name.clone(),
+                            required: required.contains(&name.as_str()),
+                            schema: hoist_refs(schema, &mut schemas),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let result_schema = tool.returns.clone().unwrap_or(serde_json::Value::Null);
+
+            OpenRpcMethod {
+                name: tool.name.clone(),
+                params,
+                result: OpenRpcContentDescriptor {
+                    name: "result".to_string(),
+                    required: true,
+                    schema: hoist_refs(&result_schema, &mut schemas),
+                },
+            }
+        })
+        .collect();
+
+    OpenRpcDocument {
+        openrpc: "1.3.2".to_string(),
+        info: OpenRpcInfo {
+            title: "MCP Server".to_string(),
+            version: "0.1.0".to_string(),
+        },
+        methods,
+        components: OpenRpcComponents { schemas },
+    }
+}
+
+/// Recursively hoists any named sub-schema (an object with a `title`) into `schemas`,
+/// keyed by that title, and replaces it in the returned value with a `$ref` pointing
+/// at `#/components/schemas/<title>`, deduplicating repeated sub-schemas by title.
+/// Recursion descends only through `properties` and `items`; titled schemas under other
+/// keywords (e.g. `allOf`, `additionalProperties`) are left inline.
+fn hoist_refs(
+    schema: &serde_json::Value,
+    schemas: &mut HashMap<String, serde_json::Value>,
+) -> serde_json::Value {
+    let Some(obj) = schema.as_object() else {
+        return schema.clone();
+    };
+
+    let mut obj = obj.clone();
+    if let Some(props) = obj.get("properties").and_then(|p| p.as_object()).cloned() {
+        let hoisted = props
+            .into_iter()
+            .map(|(name, sub)| (name, hoist_refs(&sub, schemas)))
+            .collect();
+        obj.insert("properties".to_string(), serde_json::Value::Object(hoisted));
+    }
+    if let Some(items) = obj.get("items").cloned() {
+        obj.insert("items".to_string(), hoist_refs(&items, schemas));
+    }
+
+    match obj.get("title").and_then(|t| t.as_str()) {
+        Some(title) => {
+            let title = title.to_string();
+            schemas
+                .entry(title.clone())
+                .or_insert_with(|| serde_json::Value::Object(obj));
+            serde_json::json!({ "$ref": format!("#/components/schemas/{title}") })
+        }
+        None => serde_json::Value::Object(obj),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceTemplate {
     pub name: String,
@@ -146,3 +417,345 @@ pub struct Root {
     pub uri: String,
     pub annotations: Option<Annotations>,
 }
+
+/// Identifies a JSON-RPC request so its response can be correlated back to it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum RequestId {
+    String(String),
+    Number(i64),
+}
+
+/// The error object returned in a JSON-RPC `Error` message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<serde_json::Value>,
+}
+
+/// Standard JSON-RPC 2.0 error codes.
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+
+/// MCP-specific JSON-RPC error codes, reserved in the range below -32000.
+pub const REQUEST_CANCELLED: i64 = -32800;
+pub const CONTENT_MODIFIED: i64 = -32801;
+
+/// A JSON-RPC 2.0 wire message. This is the envelope that wraps MCP request/result
+/// payloads (e.g. `CallToolRequest`, `CallToolResult`) for transport between client and server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsonRpcMessage {
+    Request {
+        jsonrpc: String,
+        id: RequestId,
+        method: String,
+        params: Option<serde_json::Value>,
+    },
+    Notification {
+        jsonrpc: String,
+        method: String,
+        params: Option<serde_json::Value>,
+    },
+    Response {
+        jsonrpc: String,
+        id: RequestId,
+        result: serde_json::Value,
+    },
+    Error {
+        jsonrpc: String,
+        id: RequestId,
+        error: JsonRpcError,
+    },
+}
+
+impl JsonRpcMessage {
+    /// Wraps a method and params into a JSON-RPC request envelope.
+    pub fn request(id: RequestId, method: impl Into<String>, params: impl Serialize) -> Self {
+        JsonRpcMessage::Request {
+            jsonrpc: "2.0".to_string(),
+            id,
+            method: method.into(),
+            params: serde_json::to_value(params).ok(),
+        }
+    }
+
+    /// Wraps a method and params into a JSON-RPC notification envelope.
+    pub fn notification(method: impl Into<String>, params: impl Serialize) -> Self {
+        JsonRpcMessage::Notification {
+            jsonrpc: "2.0".to_string(),
+            method: method.into(),
+            params: serde_json::to_value(params).ok(),
+        }
+    }
+
+    /// Wraps a result payload (e.g. `CallToolResult`) into a JSON-RPC response envelope.
+    pub fn response(id: RequestId, result: impl Serialize) -> Self {
+        JsonRpcMessage::Response {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: serde_json::to_value(result).unwrap_or(serde_json::Value::Null),
+        }
+    }
+
+    /// Wraps an error into a JSON-RPC error envelope.
+    pub fn error(id: RequestId, error: JsonRpcError) -> Self {
+        JsonRpcMessage::Error {
+            jsonrpc: "2.0".to_string(),
+            id,
+            error,
+        }
+    }
+
+    /// Extracts and deserializes the params/result payload of this message, if any.
+    pub fn extract<T: serde::de::DeserializeOwned>(&self) -> Option<T> {
+        let value = match self {
+            JsonRpcMessage::Request { params, .. } => params.clone(),
+            JsonRpcMessage::Notification { params, .. } => params.clone(),
+            JsonRpcMessage::Response { result, .. } => Some(result.clone()),
+            JsonRpcMessage::Error { .. } => None,
+        }?;
+        serde_json::from_value(value).ok()
+    }
+}
+
+/// A list result that can be paged through via an opaque cursor.
+pub trait Paginated {
+    fn next_cursor(&self) -> Option<&str>;
+}
+
+/// Lists the tools a server provides, one page at a time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListToolsRequest {
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListToolsResult {
+    pub tools: Vec<Tool>,
+    pub next_cursor: Option<String>,
+}
+
+impl Paginated for ListToolsResult {
+    fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+}
+
+/// Lists the resources a server provides, one page at a time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListResourcesRequest {
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListResourcesResult {
+    pub resources: Vec<Resource>,
+    pub next_cursor: Option<String>,
+}
+
+impl Paginated for ListResourcesResult {
+    fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+}
+
+/// Lists the prompts a server provides, one page at a time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListPromptsRequest {
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListPromptsResult {
+    pub prompts: Vec<ResourceTemplate>,
+    pub next_cursor: Option<String>,
+}
+
+impl Paginated for ListPromptsResult {
+    fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+}
+
+/// Lists the resource templates a server provides, one page at a time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListResourceTemplatesRequest {
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListResourceTemplatesResult {
+    pub resource_templates: Vec<ResourceTemplate>,
+    pub next_cursor: Option<String>,
+}
+
+impl Paginated for ListResourceTemplatesResult {
+    fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_tools_result_next_cursor_some_and_none() {
+        let page = ListToolsResult {
+            tools: vec![],
+            next_cursor: Some("page-2".to_string()),
+        };
+        assert_eq!(page.next_cursor(), Some("page-2"));
+
+        let last_page = ListToolsResult {
+            tools: vec![],
+            next_cursor: None,
+        };
+        assert_eq!(last_page.next_cursor(), None);
+    }
+
+    #[test]
+    fn logging_level_is_ordered_by_severity() {
+        assert!(LoggingLevel::Debug < LoggingLevel::Emergency);
+        assert!(LoggingLevel::Warning < LoggingLevel::Critical);
+    }
+
+    #[test]
+    fn initialize_request_and_result_round_trip_through_json() {
+        let request = InitializeRequest {
+            method: "initialize".to_string(),
+            params: InitializeParams {
+                capabilities: ClientCapabilities {
+                    roots: None,
+                    sampling: None,
+                    experimental: None,
+                },
+                client_info: Implementation {
+                    name: "test-client".to_string(),
+                    version: "1.0.0".to_string(),
+                    homepage: None,
+                },
+                protocol_version: "2025-03-26".to_string(),
+                supported_protocol_versions: Some(vec!["2024-11-05".to_string()]),
+            },
+        };
+        let json = serde_json::to_string(&request).expect("request should serialize");
+        let round_tripped: InitializeRequest =
+            serde_json::from_str(&json).expect("request should deserialize");
+        assert_eq!(
+            round_tripped.params.supported_protocol_versions,
+            Some(vec!["2024-11-05".to_string()])
+        );
+
+        let result = InitializeResult {
+            capabilities: ServerCapabilities {
+                experimental: None,
+                tools: None,
+                prompts: None,
+                progress: None,
+                logging: None,
+            },
+            server_info: Implementation {
+                name: "test-server".to_string(),
+                version: "1.0.0".to_string(),
+                homepage: None,
+            },
+            protocol_version: "2025-03-26".to_string(),
+            supported_protocol_versions: Some(vec!["2024-11-05".to_string()]),
+            meta: None,
+        };
+        let json = serde_json::to_string(&result).expect("result should serialize");
+        let round_tripped: InitializeResult =
+            serde_json::from_str(&json).expect("result should deserialize");
+        assert_eq!(round_tripped.server_info.name, "test-server");
+        assert_eq!(
+            round_tripped.supported_protocol_versions,
+            Some(vec!["2024-11-05".to_string()])
+        );
+    }
+
+    #[test]
+    fn json_rpc_message_request_response_round_trip() {
+        let request = JsonRpcMessage::request(
+            RequestId::Number(1),
+            "tools/call",
+            CallToolParams {
+                name: "echo".to_string(),
+                arguments: None,
+                meta: None,
+            },
+        );
+        let params: CallToolParams = request.extract().expect("params should deserialize");
+        assert_eq!(params.name, "echo");
+
+        let response = JsonRpcMessage::response(
+            RequestId::Number(1),
+            CallToolResult {
+                content: vec![],
+                is_error: Some(false),
+                meta: None,
+            },
+        );
+        let result: CallToolResult = response.extract().expect("result should deserialize");
+        assert_eq!(result.is_error, Some(false));
+    }
+
+    #[test]
+    fn to_openrpc_hoists_titled_sub_schema_as_ref() {
+        let capabilities = ServerCapabilities {
+            experimental: None,
+            tools: Some(vec![Tool {
+                name: "create_widget".to_string(),
+                description: "Creates a widget".to_string(),
+                parameters: serde_json::json!({
+                    "properties": {
+                        "widget": {
+                            "title": "Widget",
+                            "properties": { "id": { "type": "string" } }
+                        }
+                    },
+                    "required": ["widget"]
+                }),
+                returns: Some(serde_json::json!({ "title": "Widget" })),
+                annotations: None,
+            }]),
+            prompts: None,
+            progress: None,
+            logging: None,
+        };
+
+        let doc = to_openrpc(&capabilities);
+        let method = &doc.methods[0];
+
+        let widget_ref = serde_json::json!({ "$ref": "#/components/schemas/Widget" });
+        assert_eq!(method.params[0].schema, widget_ref);
+        assert_eq!(method.result.schema, widget_ref);
+        assert_eq!(doc.components.schemas.len(), 1);
+        assert_eq!(
+            doc.components.schemas["Widget"]["properties"]["id"]["type"],
+            "string"
+        );
+    }
+
+    #[test]
+    fn verify_digest_matches_only_the_correct_content() {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        use sha2::{Digest, Sha256};
+
+        let content = b"hello mcp";
+        let digest = STANDARD.encode(Sha256::digest(content));
+        let integrity = ResourceIntegrity {
+            algorithm: DigestAlgorithm::Sha256,
+            digest,
+            signature: None,
+        };
+
+        assert!(integrity.verify_digest(content));
+        assert!(!integrity.verify_digest(b"tampered"));
+    }
+}